@@ -0,0 +1,34 @@
+// `#[tauri::command]` handlers backed by managed state, so the renderer can
+// query and control the backend synchronously instead of scraping debug
+// strings out of one-way events (`goblinos:backend-started` with payloads
+// like "spawned"/"spawn-failed").
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::state::{BackendState, BackendStatusPayload};
+use crate::supervisor::BackendSupervisor;
+
+#[tauri::command]
+pub fn backend_status(state: State<'_, Arc<BackendState>>) -> BackendStatusPayload {
+    state.snapshot()
+}
+
+#[tauri::command]
+pub fn start_backend(supervisor: State<'_, Arc<BackendSupervisor>>) {
+    supervisor.start();
+}
+
+#[tauri::command]
+pub fn stop_backend(supervisor: State<'_, Arc<BackendSupervisor>>) {
+    supervisor.stop();
+}
+
+#[tauri::command]
+pub fn flush_queue(app: tauri::AppHandle) {
+    use tauri::Emitter;
+    // Flushing the work queue is owned by the Python backend; the Hub just
+    // relays the request the same way the tray's "Flush Queue" item already
+    // does for the renderer.
+    let _ = app.emit("goblinos:tray-action", "flush-queue");
+}
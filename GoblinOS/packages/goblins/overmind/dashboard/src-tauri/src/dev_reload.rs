@@ -0,0 +1,115 @@
+// Opt-in dev-mode auto-reload of the backend, borrowing the `notify` watcher
+// pattern from `millennium-cli`'s dev command. Gated behind the `dev-reload`
+// feature. The watcher runs an extra `pip install -r requirements.txt` before
+// restarting only when `requirements.txt` itself changed; the supervisor's
+// launch command (`build_command`) still runs `pip install` on every start,
+// including each reload's restart.
+#![cfg(feature = "dev-reload")]
+
+use std::path::Path;
+use std::sync::{mpsc, Arc};
+use std::time::Duration;
+
+use notify::{RecursiveMode, Watcher};
+use tauri::{AppHandle, Emitter};
+
+use crate::supervisor::BackendSupervisor;
+
+/// Coalesce bursts of filesystem events (editors often emit several events
+/// per save) into a single reload.
+const DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Directories a reload's own side effects write into — `build_command`
+/// creates `.venv` and runs `pip install`/uvicorn there, which compiles
+/// `__pycache__`. Without excluding these, each reload re-triggers the
+/// watcher on its own output, causing a reload storm.
+const IGNORED_DIRS: &[&str] = &[".venv", "__pycache__", ".git", ".hg", ".mypy_cache", ".pytest_cache"];
+
+fn is_ignored_path(path: &Path) -> bool {
+    path.components().any(|component| {
+        component
+            .as_os_str()
+            .to_str()
+            .map(|name| IGNORED_DIRS.contains(&name))
+            .unwrap_or(false)
+    })
+}
+
+/// Whether an event touches at least one path outside `IGNORED_DIRS`.
+fn is_relevant_event(event: &notify::Event) -> bool {
+    event.paths.iter().any(|p| !is_ignored_path(p))
+}
+
+/// Watches `backend_dir` recursively and restarts the supervised backend on
+/// relevant changes. Runs for the lifetime of the app on its own thread.
+pub fn spawn(app: AppHandle, supervisor: Arc<BackendSupervisor>, backend_dir: String) {
+    std::thread::spawn(move || {
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = match notify::recommended_watcher(tx) {
+            Ok(watcher) => watcher,
+            Err(err) => {
+                let _ = app.emit("goblinos:backend-reloading", format!("watcher-error: {err}"));
+                return;
+            }
+        };
+
+        if watcher
+            .watch(Path::new(&backend_dir), RecursiveMode::Recursive)
+            .is_err()
+        {
+            return;
+        }
+
+        loop {
+            // Wait for an event that isn't just the previous reload's own
+            // `.venv`/`__pycache__` writes — otherwise a reload's side
+            // effects immediately queue up the next one.
+            let first_event = loop {
+                match rx.recv() {
+                    Ok(event @ Ok(ref inner)) if is_relevant_event(inner) => break event,
+                    Ok(_) => continue,
+                    Err(_) => return,
+                }
+            };
+            let mut changed_paths = vec![first_event];
+
+            // Drain anything else that arrives within the debounce window so
+            // a burst of saves collapses into a single reload. Everything
+            // below this point (pip install, supervisor stop/start) runs
+            // synchronously on this same thread, so further filesystem
+            // events just queue up in `rx` until we loop back to `recv()` —
+            // there's no second thread that could start an overlapping
+            // reload, which is what guards against reload storms here.
+            while let Ok(event) = rx.recv_timeout(DEBOUNCE) {
+                changed_paths.push(event);
+            }
+
+            let requirements_changed = changed_paths.iter().any(|res| {
+                res.as_ref()
+                    .ok()
+                    .map(|event| {
+                        event
+                            .paths
+                            .iter()
+                            .any(|p| p.file_name().map(|n| n == "requirements.txt").unwrap_or(false))
+                    })
+                    .unwrap_or(false)
+            });
+
+            let _ = app.emit("goblinos:backend-reloading", requirements_changed);
+
+            if requirements_changed {
+                let _ = std::process::Command::new("sh")
+                    .arg("-c")
+                    .arg(format!(
+                        "cd {} && source .venv/bin/activate 2>/dev/null || true; pip install -r requirements.txt",
+                        backend_dir
+                    ))
+                    .status();
+            }
+
+            supervisor.stop();
+            supervisor.start();
+        }
+    });
+}
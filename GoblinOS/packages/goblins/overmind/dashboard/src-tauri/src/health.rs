@@ -0,0 +1,110 @@
+// Continuous backend health monitor, spawned on Tauri's async runtime the
+// same way the updater drives its own background loop in upstream Tauri's
+// `app.rs`. Replaces the synchronous `TcpStream::connect_timeout` probes that
+// used to block the UI thread inside `setup` and only ever ran once at
+// launch.
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+
+use crate::supervisor::BackendSupervisor;
+
+/// Tunables for the health loop. Previously these were hardcoded as
+/// `"127.0.0.1:8001"` / `200ms` scattered through `setup`.
+#[derive(Clone, Debug)]
+pub struct HealthConfig {
+    pub port: u16,
+    pub interval: Duration,
+    pub failure_threshold: u32,
+    /// How long the backend may stay Unreachable before the health loop
+    /// triggers a supervisor restart.
+    pub unreachable_grace: Duration,
+}
+
+impl Default for HealthConfig {
+    fn default() -> Self {
+        Self {
+            port: 8001,
+            interval: Duration::from_secs(2),
+            failure_threshold: 3,
+            unreachable_grace: Duration::from_secs(15),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthState {
+    Running,
+    Degraded,
+    Unreachable,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct HealthEvent {
+    status: HealthState,
+    latency_ms: Option<u64>,
+}
+
+/// Spawns the periodic `/health` poll loop. Returns immediately; the loop
+/// runs for the lifetime of the app on Tauri's async runtime.
+pub fn spawn(app: AppHandle, supervisor: Arc<BackendSupervisor>, config: HealthConfig) {
+    tauri::async_runtime::spawn(async move {
+        let client = reqwest::Client::new();
+        let url = format!("http://127.0.0.1:{}/health", config.port);
+        let consecutive_failures = AtomicU32::new(0);
+        let mut unreachable_since: Option<Instant> = None;
+
+        loop {
+            tokio::time::sleep(config.interval).await;
+
+            let started = Instant::now();
+            let result = client
+                .get(&url)
+                .timeout(config.interval)
+                .send()
+                .await
+                .ok()
+                .filter(|resp| resp.status().is_success());
+            let latency = started.elapsed();
+
+            let state = if result.is_some() {
+                consecutive_failures.store(0, Ordering::SeqCst);
+                unreachable_since = None;
+                HealthState::Running
+            } else {
+                let failures = consecutive_failures.fetch_add(1, Ordering::SeqCst) + 1;
+                if failures >= config.failure_threshold {
+                    unreachable_since.get_or_insert(Instant::now());
+                    HealthState::Unreachable
+                } else {
+                    HealthState::Degraded
+                }
+            };
+
+            let _ = app.emit(
+                "goblinos:backend-health",
+                HealthEvent {
+                    status: state,
+                    latency_ms: result.map(|_| latency.as_millis() as u64),
+                },
+            );
+
+            if let Some(since) = unreachable_since {
+                // Don't force-restart a backend the user deliberately
+                // stopped (e.g. via tray "Stop Agents") just because it's
+                // unreachable for longer than the grace period — that's
+                // expected, not a health problem.
+                if since.elapsed() >= config.unreachable_grace && !supervisor.is_user_stopped() {
+                    supervisor.stop();
+                    supervisor.start();
+                    unreachable_since = None;
+                    consecutive_failures.store(0, Ordering::SeqCst);
+                }
+            }
+        }
+    });
+}
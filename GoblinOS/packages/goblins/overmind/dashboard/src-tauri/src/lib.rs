@@ -2,11 +2,37 @@
 use tauri::Emitter;
 // Import the Listener trait to get AppHandle.listen
 use tauri::Listener;
+use tauri::Manager;
 
 // Use the `tray-icon` crate (added as a dependency in Cargo.toml)
 use tray_icon::{TrayIconBuilder, TrayIconEvent};
-use tray_icon::menu::{Menu, MenuEvent, MenuItem};
+use tray_icon::menu::{Menu, MenuEvent, MenuId, MenuItem};
 use std::cell::RefCell;
+use std::sync::Arc;
+
+mod commands;
+#[cfg(feature = "dev-reload")]
+mod dev_reload;
+mod health;
+mod state;
+mod supervisor;
+#[cfg(target_os = "linux")]
+mod tray_linux;
+#[cfg(feature = "updater")]
+mod updater;
+use state::BackendState;
+use supervisor::BackendSupervisor;
+
+/// Stable ids for the tray menu items, so menu clicks are matched by id
+/// instead of parsing the `Debug` output of the item's label.
+const MENU_ID_START: &str = "start-agents";
+const MENU_ID_STOP: &str = "stop-agents";
+const MENU_ID_OPEN_LOGS: &str = "open-logs";
+const MENU_ID_FLUSH_QUEUE: &str = "flush-queue";
+
+/// Where the GoblinOS API backend lives, relative to the Hub process's
+/// working directory.
+const BACKEND_DIR: &str = "../../../../../../GoblinOS/packages/goblins/overmind/api";
 
 // Keep the tray icon in a thread-local RefCell so it can be created on the UI thread
 // and doesn't need to be Send/Sync.
@@ -52,50 +78,64 @@ pub fn run() {
 
       // Create a tray icon with quick actions and keep it in TRAY_HANDLE so it stays alive.
       // Menu entries are forwarded to the frontend via events so the renderer can act.
-  let menu = Menu::new();
-  // MenuItem::new(text, enabled, accelerator)
-  let start_item = MenuItem::new("Start Agents", true, None);
-  let stop_item = MenuItem::new("Stop Agents", true, None);
-  let open_logs = MenuItem::new("Open Logs", true, None);
-  let flush_queue = MenuItem::new("Flush Queue", true, None);
-  // Append items (API expects owned items)
-  let _ = menu.append(&start_item);
-  let _ = menu.append(&stop_item);
-  // Add a disabled separator-like item (cross-platform) since the
-  // menu API doesn't expose a direct separator helper in this version.
-  let _ = menu.append(&MenuItem::new("—", false, None));
-  let _ = menu.append(&open_logs);
-  let _ = menu.append(&flush_queue);
+      //
+      // `tray-icon`'s native (X11) tray does not show up reliably on modern
+      // Linux desktops (Wayland, GNOME without extensions), so on Linux we
+      // register our own StatusNotifierItem over DBus instead — see
+      // `tray_linux`. Everywhere else this is the tray.
+      #[cfg(not(target_os = "linux"))]
+      {
+        let menu = Menu::new();
+        // MenuItem::with_id(id, text, enabled, accelerator) — keying items off a
+        // stable MenuId lets the click handler below match on `event.id()` instead
+        // of parsing the item's label out of a Debug-formatted event.
+        let start_item = MenuItem::with_id(MenuId::new(MENU_ID_START), "Start Agents", true, None);
+        let stop_item = MenuItem::with_id(MenuId::new(MENU_ID_STOP), "Stop Agents", true, None);
+        let open_logs = MenuItem::with_id(MenuId::new(MENU_ID_OPEN_LOGS), "Open Logs", true, None);
+        let flush_queue = MenuItem::with_id(MenuId::new(MENU_ID_FLUSH_QUEUE), "Flush Queue", true, None);
+        // Append items (the API takes them by reference)
+        let _ = menu.append(&start_item);
+        let _ = menu.append(&stop_item);
+        // Add a disabled separator-like item (cross-platform) since the
+        // menu API doesn't expose a direct separator helper in this version.
+        let _ = menu.append(&MenuItem::new("—", false, None));
+        let _ = menu.append(&open_logs);
+        let _ = menu.append(&flush_queue);
 
-      if let Ok(tray_icon) = TrayIconBuilder::new()
-        .with_tooltip("GoblinOS Hub")
-        .with_menu(Box::new(menu))
-        .build()
+        if let Ok(tray_icon) = TrayIconBuilder::new()
+          .with_tooltip("GoblinOS Hub")
+          .with_menu(Box::new(menu))
+          .build()
+        {
+          TRAY_HANDLE.with(|cell| {
+            *cell.borrow_mut() = Some(tray_icon);
+          });
+        }
+      }
+
+      // On Linux, register the SNI tray instead. If no StatusNotifierWatcher
+      // is present on the session bus (no compatible status bar running),
+      // `register` emits a `goblinos:tray-diagnostic` event and returns
+      // `None` so the app continues in window-only mode rather than
+      // silently having a dead tray.
+      #[cfg(target_os = "linux")]
       {
-        TRAY_HANDLE.with(|cell| {
-          *cell.borrow_mut() = Some(tray_icon);
+        let tray_app_handle = app.handle().clone();
+        tauri::async_runtime::spawn(async move {
+          let _connection = tray_linux::register(tray_app_handle).await;
+          // Hold the connection open for the lifetime of the app by leaking
+          // it into this task, which just parks forever once registration
+          // finishes (or immediately returns if registration failed).
+          if _connection.is_some() {
+            std::future::pending::<()>().await;
+          }
         });
       }
 
-      // Spawn the GoblinOS API backend (best-effort, non-blocking), but ensure
-      // only a single instance is started. We use a two-step approach:
-      // 1) attempt a short TCP connect to the backend port (127.0.0.1:8001).
-      //    If it responds, assume the backend is already running and skip spawn.
-      // 2) if not reachable, create an exclusive lock file in the system temp
-      //    directory using create_new(true). This prevents races where multiple
-      //    instances try to spawn the backend simultaneously. If the lock file
-      //    appears stale (port still closed), we remove it and retry a few times.
-      use std::net::TcpStream;
-      use std::time::Duration;
-  use std::fs::{OpenOptions, remove_file};
-  use std::io::Write;
-
-      let backend_addr = "127.0.0.1:8001";
-      let lock_path = std::env::temp_dir().join("goblinos_overmind_api.lock");
-
       // Single-instance lock for the Hub process itself to avoid spawning multiple
       // instances which could each attempt to start backends. If the lock exists
       // we exit early. This is a best-effort local lock using create_new semantics.
+      use std::io::Write;
       let hub_lock = std::env::temp_dir().join("goblinos_overmind_hub.lock");
       match std::fs::OpenOptions::new().write(true).create_new(true).open(&hub_lock) {
         Ok(mut f) => {
@@ -111,72 +151,39 @@ pub fn run() {
         }
       }
 
-      let backend_running = TcpStream::connect_timeout(&backend_addr.parse().unwrap(), Duration::from_millis(200)).is_ok();
+      // Hand the backend lifecycle over to the supervisor: it owns the
+      // SharedChild, forwards its stdout/stderr as log events, and restarts
+      // it with backoff if it exits unexpectedly. Status is tracked in
+      // `BackendState`, managed separately so `backend_status()` can be
+      // called without going through the supervisor.
+      let backend_state = Arc::new(BackendState::new(8001));
+      let supervisor = Arc::new(BackendSupervisor::new(
+        app.handle().clone(),
+        BACKEND_DIR.to_string(),
+        backend_state.clone(),
+      ));
+      supervisor.start();
+      app.manage(backend_state);
+      app.manage(supervisor.clone());
 
-    // Try to read a PID from the lock file and check liveness.
-      if backend_running {
-        // Backend already listening — nothing to do.
-        let _ = app.handle().emit("goblinos:backend-started", "already-running");
-      } else {
-        // Try to acquire lock and spawn if we get it.
-        let mut spawned = false;
-        for _attempt in 0..3 {
-          // If backend became available in the meantime, stop.
-          if TcpStream::connect_timeout(&backend_addr.parse().unwrap(), Duration::from_millis(200)).is_ok() {
-            let _ = app.handle().emit("goblinos:backend-started", "started-by-other");
-            spawned = true;
-            break;
-          }
-
-          match OpenOptions::new().write(true).create_new(true).open(&lock_path) {
-            Ok(mut file) => {
-              // We acquired the lock — spawn the backend.
-          if let Ok(mut child) = std::process::Command::new("sh")
-                .arg("-c")
-                .arg("cd ../../../../../../GoblinOS/packages/goblins/overmind/api && \n                  if command -v python3 >/dev/null 2>&1; then PY=python3; elif command -v python >/dev/null 2>&1; then PY=python; else echo 'no-python'; fi; \n                  $PY -m venv .venv 2>/dev/null || true; source .venv/bin/activate 2>/dev/null || true; $PY -m pip install -r requirements.txt 2>/dev/null || true; PYTHONUNBUFFERED=1 $PY -m uvicorn app.main:app --host 127.0.0.1 --port 8001")
-                .spawn()
-              {
-                // Write child's pid into the lock file for diagnostics.
-                let _ = write!(file, "{}", child.id());
-                let _ = file.flush();
-                let _ = app.handle().emit("goblinos:backend-started", "spawned");
-                spawned = true;
-            // Spawn a reaper thread that waits for the backend to exit and
-            // then removes the lock file to avoid leaving stale locks.
-            let lock_clone = lock_path.clone();
-            std::thread::spawn(move || {
-              let _ = child.wait();
-              let _ = std::fs::remove_file(&lock_clone);
-            });
-                // Detach: don't wait, let it run independently.
-              } else {
-                // Spawn failed; remove lock so others can try.
-                let _ = remove_file(&lock_path);
-              }
+      // Continuously poll the backend's `/health` endpoint instead of the
+      // one-shot blocking TCP probes this used to do in `setup`. On sustained
+      // unreachability, the health loop restarts the backend through the
+      // supervisor.
+      health::spawn(app.handle().clone(), supervisor.clone(), health::HealthConfig::default());
 
-              break;
-            }
-            Err(_) => {
-              // Lock file exists — check if it's stale. If the backend is still
-              // not listening, treat the lock as stale and remove it, then retry.
-              if TcpStream::connect_timeout(&backend_addr.parse().unwrap(), Duration::from_millis(200)).is_ok() {
-                let _ = app.handle().emit("goblinos:backend-started", "started-by-other");
-                spawned = true;
-                break;
-              }
-              // Otherwise remove stale lock and retry (race: tolerable).
-              let _ = remove_file(&lock_path);
-              // short backoff
-              std::thread::sleep(Duration::from_millis(150));
-            }
-          }
-        }
+      // Gated behind the `updater` cargo feature (maps to pulling in the
+      // ed25519-dalek dep) so builds on pinned Tauri versions that
+      // don't want the extra weight still compile without it. Build with
+      // `--features updater` to enable backend self-updates.
+      #[cfg(feature = "updater")]
+      updater::register(app.handle().clone(), supervisor.clone());
 
-        if !spawned {
-          // If we failed to spawn after retries, emit a warning event but do not panic.
-          let _ = app.handle().emit("goblinos:backend-started", "spawn-failed");
-        }
-      }
+      // Opt-in dev-mode auto-reload: only wire the watcher up in debug
+      // builds with the `dev-reload` feature enabled, so release builds
+      // never pay for it.
+      #[cfg(all(feature = "dev-reload", debug_assertions))]
+      dev_reload::spawn(app.handle().clone(), supervisor, BACKEND_DIR.to_string());
 
       // NOTE: Global shortcut registration was removed because the
       // `global-shortcut` feature is not available for the pinned tauri
@@ -209,19 +216,25 @@ pub fn run() {
       // goblinos:tray-action event with one of the known action names.
       let menu_forward = app.handle().clone();
       MenuEvent::set_event_handler(Some(move |m_event| {
-        // menu event Debug will include id; match on that id string to map action
-        // Example debug: MenuEvent { id: "open_logs", .. }
-        let payload = format!("{:?}", m_event);
-        if payload.contains("Start Agents") {
+        // Match on the stable MenuId assigned when the item was created,
+        // instead of parsing the item's label out of a Debug-formatted event.
+        let id = m_event.id().0.as_str();
+        if id == MENU_ID_START {
+          if let Some(supervisor) = menu_forward.try_state::<Arc<BackendSupervisor>>() {
+            supervisor.start();
+          }
           let _ = menu_forward.emit("goblinos:tray-action", "start-agents");
-        } else if payload.contains("Stop Agents") {
+        } else if id == MENU_ID_STOP {
+          if let Some(supervisor) = menu_forward.try_state::<Arc<BackendSupervisor>>() {
+            supervisor.stop();
+          }
           let _ = menu_forward.emit("goblinos:tray-action", "stop-agents");
-        } else if payload.contains("Open Logs") {
+        } else if id == MENU_ID_OPEN_LOGS {
           let _ = menu_forward.emit("goblinos:tray-action", "open-logs");
-        } else if payload.contains("Flush Queue") {
+        } else if id == MENU_ID_FLUSH_QUEUE {
           let _ = menu_forward.emit("goblinos:tray-action", "flush-queue");
         } else {
-          let _ = menu_forward.emit("goblinos:tray-menu", payload);
+          let _ = menu_forward.emit("goblinos:tray-menu", format!("{:?}", m_event));
         }
       }));
 
@@ -266,6 +279,12 @@ pub fn run() {
 
       Ok(())
     })
+    .invoke_handler(tauri::generate_handler![
+      commands::backend_status,
+      commands::start_backend,
+      commands::stop_backend,
+      commands::flush_queue,
+    ])
     .run(tauri::generate_context!())
     .expect("error while running tauri application");
 }
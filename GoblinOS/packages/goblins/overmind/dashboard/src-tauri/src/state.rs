@@ -0,0 +1,81 @@
+// Shared backend status, tracked in managed state so both the tray menu and
+// the `#[tauri::command]` handlers in `commands.rs` read from one source of
+// truth instead of each scraping debug-formatted event payloads.
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use serde::Serialize;
+
+/// How many trailing log lines are kept for `backend_status()` to return.
+const LOG_HISTORY: usize = 50;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackendStatus {
+    Stopped,
+    Starting,
+    Running,
+    Crashed,
+    GaveUp,
+}
+
+struct Inner {
+    status: BackendStatus,
+    port: u16,
+    pid: Option<u32>,
+    logs: VecDeque<String>,
+}
+
+/// Managed state holding the current view of the supervised backend. Shared
+/// as an `Arc<BackendState>` (that `Arc` is the managed type), so the
+/// supervisor and the command handlers all hold the same instance.
+pub struct BackendState {
+    inner: Mutex<Inner>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct BackendStatusPayload {
+    pub status: BackendStatus,
+    pub port: u16,
+    pub pid: Option<u32>,
+    pub logs: Vec<String>,
+}
+
+impl BackendState {
+    pub fn new(port: u16) -> Self {
+        Self {
+            inner: Mutex::new(Inner {
+                status: BackendStatus::Stopped,
+                port,
+                pid: None,
+                logs: VecDeque::with_capacity(LOG_HISTORY),
+            }),
+        }
+    }
+
+    pub fn set_status(&self, status: BackendStatus) {
+        self.inner.lock().unwrap().status = status;
+    }
+
+    pub fn set_pid(&self, pid: Option<u32>) {
+        self.inner.lock().unwrap().pid = pid;
+    }
+
+    pub fn push_log(&self, line: String) {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.logs.len() == LOG_HISTORY {
+            inner.logs.pop_front();
+        }
+        inner.logs.push_back(line);
+    }
+
+    pub fn snapshot(&self) -> BackendStatusPayload {
+        let inner = self.inner.lock().unwrap();
+        BackendStatusPayload {
+            status: inner.status,
+            port: inner.port,
+            pid: inner.pid,
+            logs: inner.logs.iter().cloned().collect(),
+        }
+    }
+}
@@ -0,0 +1,217 @@
+// Supervises the GoblinOS Python backend as a long-lived child process.
+//
+// Modeled on how `millennium-cli`'s dev loop manages its child process: the
+// child is wrapped in a `shared_child::SharedChild` so it can be started,
+// killed, and waited on from more than one thread without juggling raw PIDs.
+// stdout/stderr are piped and forwarded to the frontend as log events, and a
+// monitor thread restarts the backend with exponential backoff if it exits
+// unexpectedly.
+use std::io::{BufRead, BufReader};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use shared_child::SharedChild;
+use tauri::{AppHandle, Emitter};
+
+use crate::state::{BackendState, BackendStatus};
+
+/// Initial backoff delay before the first restart attempt.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(200);
+/// Backoff never waits longer than this between restart attempts.
+const MAX_BACKOFF: Duration = Duration::from_secs(10);
+/// A backend that stays up this long is considered healthy and resets backoff.
+const HEALTHY_UPTIME: Duration = Duration::from_secs(30);
+/// Give up restarting after this many consecutive fast failures.
+const MAX_CONSECUTIVE_FAILURES: u32 = 5;
+
+/// Shared handle to the supervised backend child, if one is currently running.
+pub type SharedChildSlot = Arc<Mutex<Option<Arc<SharedChild>>>>;
+
+/// Everything the supervisor needs to (re)launch the backend.
+pub struct BackendSupervisor {
+    app: AppHandle,
+    child: SharedChildSlot,
+    state: Arc<BackendState>,
+    /// Set by `stop()` so the monitor thread knows an exit was requested and
+    /// should not trigger a restart.
+    user_stopped: Arc<AtomicBool>,
+    /// Bumped by every `stop()` call and by every `start()` that launches a
+    /// backend (not one that returns early). A monitor thread captures the
+    /// generation it was spawned with and treats itself as superseded once
+    /// this no longer matches — see the comment in `spawn_and_monitor` for
+    /// why this is needed to avoid a back-to-back stop()/start() racing the
+    /// old monitor thread's `wait()`.
+    generation: Arc<AtomicU64>,
+    command_dir: String,
+}
+
+impl BackendSupervisor {
+    pub fn new(app: AppHandle, command_dir: String, state: Arc<BackendState>) -> Self {
+        Self {
+            app,
+            child: Arc::new(Mutex::new(None)),
+            state,
+            user_stopped: Arc::new(AtomicBool::new(false)),
+            generation: Arc::new(AtomicU64::new(0)),
+            command_dir,
+        }
+    }
+
+    pub fn child_slot(&self) -> SharedChildSlot {
+        self.child.clone()
+    }
+
+    /// Whether the backend was most recently stopped by the user (via the
+    /// tray or a `stop_backend` command) rather than by crashing. Callers
+    /// like the health monitor should not treat this as grounds to restart.
+    pub fn is_user_stopped(&self) -> bool {
+        self.user_stopped.load(Ordering::SeqCst)
+    }
+
+    /// Starts the backend if it isn't already running, spawning the monitor
+    /// thread that restarts it on unexpected exit.
+    pub fn start(&self) {
+        if self.child.lock().unwrap().is_some() {
+            return;
+        }
+        let generation = self.generation.fetch_add(1, Ordering::SeqCst) + 1;
+        self.user_stopped.store(false, Ordering::SeqCst);
+        self.state.set_status(BackendStatus::Starting);
+        self.spawn_and_monitor(generation);
+    }
+
+    /// Kills the running backend (if any) and marks it as user-stopped so the
+    /// monitor thread does not restart it.
+    pub fn stop(&self) {
+        // Bump the generation first so any monitor thread still parked in
+        // `shared.wait()` for the child we're about to kill recognizes
+        // itself as stale once the kill wakes it up, instead of clobbering
+        // whatever a subsequent `start()` puts in the slot.
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        self.user_stopped.store(true, Ordering::SeqCst);
+        if let Some(child) = self.child.lock().unwrap().take() {
+            let _ = child.kill();
+        }
+        self.state.set_status(BackendStatus::Stopped);
+        self.state.set_pid(None);
+    }
+
+    /// Spawns the backend and hands ownership of the restart loop to a
+    /// background monitor thread.
+    ///
+    /// `generation` pins this monitor to the start/stop generation active
+    /// when it was spawned. A `stop()` immediately followed by `start()`
+    /// bumps the generation counter each time, so once the killed child's
+    /// `wait()` finally returns, this monitor can tell a newer generation
+    /// already owns the slot and bail out instead of treating an
+    /// intentional kill as a crash and restarting a competing process.
+    fn spawn_and_monitor(&self, generation: u64) {
+        let app = self.app.clone();
+        let child_slot = self.child.clone();
+        let user_stopped = self.user_stopped.clone();
+        let command_dir = self.command_dir.clone();
+        let state = self.state.clone();
+        let generation_counter = self.generation.clone();
+
+        std::thread::spawn(move || {
+            let mut backoff = INITIAL_BACKOFF;
+            let mut consecutive_failures = 0u32;
+
+            loop {
+                if generation_counter.load(Ordering::SeqCst) != generation {
+                    return;
+                }
+
+                let mut command = build_command(&command_dir);
+                let shared = match SharedChild::spawn(&mut command) {
+                    Ok(child) => Arc::new(child),
+                    Err(err) => {
+                        state.set_status(BackendStatus::GaveUp);
+                        let _ = app.emit("goblinos:backend-giveup", format!("spawn-error: {err}"));
+                        return;
+                    }
+                };
+
+                forward_pipe(&app, &state, shared.take_stdout());
+                forward_pipe(&app, &state, shared.take_stderr());
+
+                *child_slot.lock().unwrap() = Some(shared.clone());
+                state.set_status(BackendStatus::Running);
+                state.set_pid(Some(shared.id()));
+                let _ = app.emit("goblinos:backend-started", "spawned");
+
+                let started_at = Instant::now();
+                let status = shared.wait();
+
+                // A newer start()/stop() already bumped the generation while
+                // we were parked in `wait()` — it owns the slot and the
+                // state now, so don't touch either and don't restart.
+                if generation_counter.load(Ordering::SeqCst) != generation {
+                    return;
+                }
+
+                *child_slot.lock().unwrap() = None;
+                state.set_pid(None);
+
+                if user_stopped.load(Ordering::SeqCst) {
+                    state.set_status(BackendStatus::Stopped);
+                    return;
+                }
+
+                let uptime = started_at.elapsed();
+                state.set_status(BackendStatus::Crashed);
+                let _ = app.emit(
+                    "goblinos:backend-crashed",
+                    format!("{:?}", status.map(|s| s.code())),
+                );
+
+                if uptime >= HEALTHY_UPTIME {
+                    backoff = INITIAL_BACKOFF;
+                    consecutive_failures = 0;
+                } else {
+                    consecutive_failures += 1;
+                }
+
+                if consecutive_failures >= MAX_CONSECUTIVE_FAILURES {
+                    state.set_status(BackendStatus::GaveUp);
+                    let _ = app.emit("goblinos:backend-giveup", consecutive_failures.to_string());
+                    return;
+                }
+
+                std::thread::sleep(backoff);
+                backoff = std::cmp::min(backoff * 2, MAX_BACKOFF);
+            }
+        });
+    }
+}
+
+fn build_command(command_dir: &str) -> Command {
+    let mut cmd = Command::new("sh");
+    cmd.arg("-c").arg(format!(
+        "cd {} && \n          if command -v python3 >/dev/null 2>&1; then PY=python3; elif command -v python >/dev/null 2>&1; then PY=python; else echo 'no-python'; fi; \n          $PY -m venv .venv 2>/dev/null || true; source .venv/bin/activate 2>/dev/null || true; $PY -m pip install -r requirements.txt 2>/dev/null || true; PYTHONUNBUFFERED=1 $PY -m uvicorn app.main:app --host 127.0.0.1 --port 8001",
+        command_dir
+    ));
+    cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
+    cmd
+}
+
+/// Reads a child's stdout/stderr line-by-line on its own thread, records it
+/// into `BackendState`'s log ring buffer, and forwards it as a
+/// `goblinos:backend-log` event.
+fn forward_pipe<R>(app: &AppHandle, state: &Arc<BackendState>, pipe: Option<R>)
+where
+    R: std::io::Read + Send + 'static,
+{
+    let Some(pipe) = pipe else { return };
+    let app = app.clone();
+    let state = state.clone();
+    std::thread::spawn(move || {
+        let reader = BufReader::new(pipe);
+        for line in reader.lines().map_while(Result::ok) {
+            state.push_log(line.clone());
+            let _ = app.emit("goblinos:backend-log", line);
+        }
+    });
+}
@@ -0,0 +1,263 @@
+// Linux tray backend speaking the StatusNotifierItem (SNI) spec directly over
+// DBus, the same approach eww used to build its systray. `tray-icon`'s native
+// X11 tray does not show up reliably on modern Linux desktops (Wayland, GNOME
+// without extensions), so on Linux we register our own SNI item instead of
+// relying on it.
+#![cfg(target_os = "linux")]
+
+use std::sync::Arc;
+
+use tauri::{AppHandle, Emitter, Manager};
+use zbus::{dbus_interface, Connection, fdo};
+
+use crate::supervisor::BackendSupervisor;
+
+const WATCHER_BUS_NAME: &str = "org.kde.StatusNotifierWatcher";
+const ITEM_BUS_PATH: &str = "/StatusNotifierItem";
+const MENU_BUS_PATH: &str = "/MenuBar";
+
+/// Actions exposed through the `com.canonical.dbusmenu` menu, mirroring the
+/// tray-icon menu used on other platforms.
+const MENU_ACTIONS: &[(i32, &str)] = &[
+    (1, "Start Agents"),
+    (2, "Stop Agents"),
+    (3, "Open Logs"),
+    (4, "Flush Queue"),
+];
+
+/// DBus object implementing `org.kde.StatusNotifierItem`. Properties are
+/// intentionally minimal — just enough for a watcher/host to render an icon
+/// and title and route activation back to us; no `ToolTip` is exposed.
+struct StatusNotifierItem {
+    app: AppHandle,
+}
+
+#[dbus_interface(name = "org.kde.StatusNotifierItem")]
+impl StatusNotifierItem {
+    #[dbus_interface(property)]
+    fn category(&self) -> &str {
+        "ApplicationStatus"
+    }
+
+    #[dbus_interface(property)]
+    fn id(&self) -> &str {
+        "goblinos-hub"
+    }
+
+    #[dbus_interface(property)]
+    fn title(&self) -> &str {
+        "GoblinOS Hub"
+    }
+
+    #[dbus_interface(property)]
+    fn status(&self) -> &str {
+        "Active"
+    }
+
+    #[dbus_interface(property)]
+    fn icon_name(&self) -> &str {
+        "goblinos-tray"
+    }
+
+    /// Hosts discover the `com.canonical.dbusmenu` object by reading this
+    /// property; without it nothing links this item to the menu served at
+    /// `MENU_BUS_PATH` and the context menu never renders.
+    #[dbus_interface(property)]
+    fn menu(&self) -> zbus::zvariant::ObjectPath<'_> {
+        zbus::zvariant::ObjectPath::from_static_str_unchecked(MENU_BUS_PATH)
+    }
+
+    /// Left-click activation. The host calls this; we just forward it as the
+    /// same `goblinos:tray-click` event the other platforms emit.
+    fn activate(&self, _x: i32, _y: i32) {
+        let _ = self.app.emit("goblinos:tray-click", "activate");
+    }
+}
+
+/// DBus object implementing `com.canonical.dbusmenu`, serving the context
+/// menu ("Start Agents / Stop Agents / Open Logs / Flush Queue") that hosts
+/// render when the user right-clicks the tray item.
+struct DbusMenu {
+    app: AppHandle,
+}
+
+#[dbus_interface(name = "com.canonical.dbusmenu")]
+impl DbusMenu {
+    /// Several hosts refuse to ask for a layout until they've read `Version`
+    /// and called `AboutToShow`, even once `Menu` is wired up on the item.
+    #[dbus_interface(property)]
+    fn version(&self) -> u32 {
+        3
+    }
+
+    /// Called by the host right before it displays the menu. We have no
+    /// dynamic content to refresh, so there's nothing to do beyond
+    /// acknowledging — `needs_update` is always `false`.
+    fn about_to_show(&self, _id: i32) -> bool {
+        false
+    }
+
+    /// `GetLayout` returns `(revision, (id, properties, children))`. We
+    /// describe a flat root with one child node per `MENU_ACTIONS` entry so
+    /// "Start Agents / Stop Agents / Open Logs / Flush Queue" actually render
+    /// — an empty children list here means hosts show an empty menu and
+    /// `event()` below never fires.
+    fn get_layout(
+        &self,
+        _parent_id: i32,
+        _recursion_depth: i32,
+        _property_names: Vec<String>,
+    ) -> zbus::fdo::Result<(
+        u32,
+        (
+            i32,
+            std::collections::HashMap<String, zbus::zvariant::OwnedValue>,
+            Vec<zbus::zvariant::OwnedValue>,
+        ),
+    )> {
+        let children = MENU_ACTIONS
+            .iter()
+            .map(|(id, label)| menu_item_node(*id, label))
+            .collect();
+        Ok((0, (0, std::collections::HashMap::new(), children)))
+    }
+
+    /// Invoked by the host when an item is clicked; `id` matches the ids in
+    /// `MENU_ACTIONS`.
+    fn event(&self, id: i32, event_id: &str, _data: zbus::zvariant::Value, _timestamp: u32) {
+        if event_id != "clicked" {
+            return;
+        }
+        let action = MENU_ACTIONS
+            .iter()
+            .find(|(action_id, _)| *action_id == id)
+            .map(|(_, label)| *label);
+
+        let Some(label) = action else { return };
+        // Mirror the non-Linux `MenuEvent` handler in `lib.rs`: call the
+        // supervisor directly rather than only emitting an event that
+        // nothing in Rust acts on.
+        let action_name = match label {
+            "Start Agents" => {
+                if let Some(supervisor) = self.app.try_state::<Arc<BackendSupervisor>>() {
+                    supervisor.start();
+                }
+                "start-agents"
+            }
+            "Stop Agents" => {
+                if let Some(supervisor) = self.app.try_state::<Arc<BackendSupervisor>>() {
+                    supervisor.stop();
+                }
+                "stop-agents"
+            }
+            "Open Logs" => "open-logs",
+            "Flush Queue" => "flush-queue",
+            _ => return,
+        };
+        let _ = self.app.emit("goblinos:tray-action", action_name);
+    }
+}
+
+/// Builds a single `(ia{sv}av)` dbusmenu node — a leaf item with a `label`
+/// and `enabled` property and no children — for the given action id/label.
+fn menu_item_node(id: i32, label: &str) -> zbus::zvariant::OwnedValue {
+    use zbus::zvariant::Value;
+
+    let mut properties: std::collections::HashMap<String, Value> = std::collections::HashMap::new();
+    properties.insert("label".to_string(), Value::new(label));
+    properties.insert("enabled".to_string(), Value::new(true));
+
+    let structure = zbus::zvariant::StructureBuilder::new()
+        .add_field(id)
+        .add_field(properties)
+        .add_field(Vec::<zbus::zvariant::OwnedValue>::new())
+        .build();
+
+    Value::Structure(structure)
+        .try_to_owned()
+        .expect("dbusmenu item node always encodes")
+}
+
+/// Registers a StatusNotifierItem on the session bus and announces it to
+/// `org.kde.StatusNotifierWatcher`. Returns the live connection (kept alive
+/// by the caller) on success, or `None` if no watcher is present — callers
+/// should fall back to window-only mode in that case.
+pub async fn register(app: AppHandle) -> Option<Connection> {
+    let connection = Connection::session().await.ok()?;
+
+    let item = StatusNotifierItem { app: app.clone() };
+    let menu = DbusMenu { app: app.clone() };
+    connection.object_server().at(ITEM_BUS_PATH, item).await.ok()?;
+    connection.object_server().at(MENU_BUS_PATH, menu).await.ok()?;
+
+    let unique_name = connection.unique_name()?.to_string();
+    connection
+        .request_name(format!("org.kde.StatusNotifierItem-{}-1", std::process::id()))
+        .await
+        .ok()?;
+
+    if !announce_to_watcher(&connection, &unique_name).await {
+        let _ = app.emit(
+            "goblinos:tray-diagnostic",
+            "no-statusnotifierwatcher: falling back to window-only mode",
+        );
+        return None;
+    }
+
+    watch_for_watcher_restart(connection.clone(), unique_name, app);
+    Some(connection)
+}
+
+/// Calls `RegisterStatusNotifierItem` on the watcher. Returns `false` if no
+/// watcher is registered on the bus at all.
+async fn announce_to_watcher(connection: &Connection, unique_name: &str) -> bool {
+    let watcher_present = match fdo::DBusProxy::new(connection).await {
+        Ok(proxy) => proxy.name_has_owner(WATCHER_BUS_NAME).await.unwrap_or(false),
+        Err(_) => false,
+    };
+    if !watcher_present {
+        return false;
+    }
+
+    let proxy = match zbus::Proxy::new(
+        connection,
+        WATCHER_BUS_NAME,
+        "/StatusNotifierWatcher",
+        WATCHER_BUS_NAME,
+    )
+    .await
+    {
+        Ok(proxy) => proxy,
+        Err(_) => return false,
+    };
+
+    proxy
+        .call_method("RegisterStatusNotifierItem", &(unique_name,))
+        .await
+        .is_ok()
+}
+
+/// The watcher can appear after we start (e.g. the status bar restarts), so
+/// re-register whenever its name reappears on the bus via `NameOwnerChanged`.
+fn watch_for_watcher_restart(connection: Connection, unique_name: String, app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let Ok(dbus_proxy) = fdo::DBusProxy::new(&connection).await else {
+            return;
+        };
+        let Ok(mut changes) = dbus_proxy.receive_name_owner_changed().await else {
+            return;
+        };
+        use futures_lite::StreamExt;
+        while let Some(signal) = changes.next().await {
+            let Ok(args) = signal.args() else { continue };
+            if args.name() != WATCHER_BUS_NAME {
+                continue;
+            }
+            if args.new_owner().is_some() {
+                if announce_to_watcher(&connection, &unique_name).await {
+                    let _ = app.emit("goblinos:tray-diagnostic", "statusnotifierwatcher-reacquired");
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,207 @@
+// Self-update subsystem for the bundled GoblinOS backend, modeled on Tauri's
+// own updater (`run_updater` / `run_updater_dialog` in upstream `app.rs`) but
+// targeted at the Python backend the Hub spawns rather than the Hub binary
+// itself. Gated behind the `updater` cargo feature so builds on pinned Tauri
+// versions still compile without it, the same way `notification` and
+// `global-shortcut` are gated.
+#![cfg(feature = "updater")]
+
+use std::sync::Arc;
+
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use serde::Deserialize;
+use tauri::{AppHandle, Emitter, Listener};
+
+use crate::supervisor::BackendSupervisor;
+
+/// Where the signed manifest describing the latest backend release lives.
+const MANIFEST_URL: &str = "https://updates.goblinos.dev/backend/manifest.json";
+
+/// How often to check for updates on a timer, in addition to reacting to an
+/// explicit `goblinos:check-update` event.
+const CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(6 * 60 * 60);
+
+/// Hex-encoded release public key, baked in at compile time via
+/// `GOBLINOS_BACKEND_UPDATE_PUBLIC_KEY` so the real key never has to be
+/// committed to source. Unset in dev/CI builds, which is why every update
+/// check hard-fails with a diagnostic instead of silently verifying
+/// signatures against a placeholder key that could never match a real one.
+const PUBLIC_KEY_HEX: Option<&str> = option_env!("GOBLINOS_BACKEND_UPDATE_PUBLIC_KEY");
+
+/// Loads and parses the embedded release key. Returns a clear error (instead
+/// of pretending to verify) when the build was not given a real key.
+fn embedded_public_key() -> Result<VerifyingKey, String> {
+    let hex_key = PUBLIC_KEY_HEX.ok_or_else(|| {
+        "no release public key embedded at build time (set GOBLINOS_BACKEND_UPDATE_PUBLIC_KEY); \
+         refusing to check for updates rather than verify against a placeholder"
+            .to_string()
+    })?;
+    let bytes = hex::decode(hex_key).map_err(|e| format!("embedded public key is not valid hex: {e}"))?;
+    let bytes: [u8; 32] = bytes
+        .try_into()
+        .map_err(|_| "embedded public key is not 32 bytes".to_string())?;
+    VerifyingKey::from_bytes(&bytes).map_err(|e| e.to_string())
+}
+
+#[derive(Debug, Deserialize)]
+struct UpdateManifest {
+    version: String,
+    artifact_url: String,
+    /// Hex-encoded detached ed25519 signature over the downloaded artifact.
+    signature: String,
+}
+
+/// Registers the `goblinos:check-update` listener and a `CHECK_INTERVAL`
+/// timer, either of which can trigger a check. Call once from `setup`.
+pub fn register(app: AppHandle, supervisor: Arc<BackendSupervisor>) {
+    let handle = app.clone();
+    let listener_supervisor = supervisor.clone();
+    app.clone().listen("goblinos:check-update", move |_event| {
+        let handle = handle.clone();
+        let supervisor = listener_supervisor.clone();
+        tauri::async_runtime::spawn(async move {
+            if let Err(err) = check_and_update(&handle, &supervisor).await {
+                let _ = handle.emit("goblinos:update-progress", format!("error: {err}"));
+            }
+        });
+    });
+
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(CHECK_INTERVAL).await;
+            if let Err(err) = check_and_update(&app, &supervisor).await {
+                let _ = app.emit("goblinos:update-progress", format!("error: {err}"));
+            }
+        }
+    });
+}
+
+async fn check_and_update(
+    app: &AppHandle,
+    supervisor: &Arc<BackendSupervisor>,
+) -> Result<(), String> {
+    // Fail fast with a clear diagnostic if this build has no real release
+    // key, rather than downloading an artifact we can only ever reject.
+    let public_key = embedded_public_key()?;
+
+    let client = reqwest::Client::new();
+
+    let manifest: UpdateManifest = client
+        .get(MANIFEST_URL)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .json()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    // A backend that's unreachable (down for a restart, still starting up)
+    // is not evidence of an outdated version — `None` here must not fall
+    // through to `""`, or every check while the backend is down would look
+    // like an update and trigger a needless download/reinstall.
+    let Some(current_version) = current_backend_version(&client).await else {
+        return Ok(());
+    };
+    if manifest.version == current_version {
+        return Ok(());
+    }
+
+    let _ = app.emit("goblinos:update-available", &manifest.version);
+
+    let artifact = download_artifact(&client, app, &manifest).await?;
+    verify_signature(&artifact, &manifest.signature, &public_key)?;
+    install(&artifact).await?;
+
+    let _ = app.emit("goblinos:update-installed", &manifest.version);
+    supervisor.stop();
+    supervisor.start();
+    Ok(())
+}
+
+#[derive(Debug, Deserialize)]
+struct VersionResponse {
+    version: String,
+}
+
+/// Fetches and normalizes the backend's reported version. The endpoint may
+/// respond with a bare version string or a `{"version": "..."}` body
+/// depending on how the backend was built, and either way the body often
+/// carries a trailing newline — compare against `manifest.version` only
+/// after parsing/trimming, never the raw response text.
+async fn current_backend_version(client: &reqwest::Client) -> Option<String> {
+    let body = client
+        .get("http://127.0.0.1:8001/version")
+        .send()
+        .await
+        .ok()?
+        .text()
+        .await
+        .ok()?;
+
+    if let Ok(parsed) = serde_json::from_str::<VersionResponse>(&body) {
+        return Some(parsed.version.trim().to_string());
+    }
+    Some(body.trim().to_string())
+}
+
+/// Downloads the artifact to a temp file, reporting progress as a fraction
+/// (0.0–1.0) via `goblinos:update-progress`.
+async fn download_artifact(
+    client: &reqwest::Client,
+    app: &AppHandle,
+    manifest: &UpdateManifest,
+) -> Result<std::path::PathBuf, String> {
+    use futures_util::StreamExt;
+    use tokio::io::AsyncWriteExt;
+
+    let response = client
+        .get(&manifest.artifact_url)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    let total = response.content_length().unwrap_or(0);
+
+    let dest = std::env::temp_dir().join(format!("goblinos-backend-{}.tar.gz", manifest.version));
+    let mut file = tokio::fs::File::create(&dest)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut downloaded: u64 = 0;
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| e.to_string())?;
+        file.write_all(&chunk).await.map_err(|e| e.to_string())?;
+        downloaded += chunk.len() as u64;
+        if total > 0 {
+            let _ = app.emit("goblinos:update-progress", downloaded as f64 / total as f64);
+        }
+    }
+
+    Ok(dest)
+}
+
+fn verify_signature(
+    artifact: &std::path::Path,
+    signature_hex: &str,
+    public_key: &VerifyingKey,
+) -> Result<(), String> {
+    let bytes = std::fs::read(artifact).map_err(|e| e.to_string())?;
+    let signature_bytes = hex::decode(signature_hex).map_err(|e| e.to_string())?;
+    let signature = Signature::from_slice(&signature_bytes).map_err(|e| e.to_string())?;
+    public_key.verify(&bytes, &signature).map_err(|e| e.to_string())
+}
+
+/// Unpacks the verified artifact over the backend's install directory.
+async fn install(artifact: &std::path::Path) -> Result<(), String> {
+    let install_dir = "../../../../../../GoblinOS/packages/goblins/overmind/api";
+    let artifact = artifact.to_path_buf();
+    let install_dir = install_dir.to_string();
+    tokio::task::spawn_blocking(move || {
+        let file = std::fs::File::open(&artifact).map_err(|e| e.to_string())?;
+        let decoder = flate2::read::GzDecoder::new(file);
+        let mut archive = tar::Archive::new(decoder);
+        archive.unpack(&install_dir).map_err(|e| e.to_string())
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}